@@ -88,6 +88,46 @@ impl Color {
             a as f32 / u8::MAX as f32,
         )
     }
+
+    /// Interpolates between this color and `other` by `t`, blending the color channels in the
+    /// given [ColorSpace]. Alpha is always interpolated linearly.
+    pub fn lerp(self, other: Color, t: f32, color_space: ColorSpace) -> Color {
+        let start = Vec3::new(self.r, self.g, self.b);
+        let end = Vec3::new(other.r, other.g, other.b);
+        let rgb = match color_space {
+            ColorSpace::LinearRgb => start.lerp(end, t),
+            ColorSpace::Srgb => srgb_to_linear(linear_to_srgb(start).lerp(linear_to_srgb(end), t)),
+            ColorSpace::Hsv => {
+                let start = srgb_to_hsv(linear_to_srgb(start));
+                let end = srgb_to_hsv(linear_to_srgb(end));
+                srgb_to_linear(hsv_to_srgb(lerp_hsv(start, end, t)))
+            }
+            ColorSpace::Oklab => {
+                oklab_to_linear(linear_to_oklab(start).lerp(linear_to_oklab(end), t))
+            }
+        };
+
+        Color::rgba(rgb.x(), rgb.y(), rgb.z(), self.a + (other.a - self.a) * t)
+    }
+}
+
+/// The color space used to interpolate between two [Color]s
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorSpace {
+    /// Interpolates the stored (linear) channel values directly
+    LinearRgb,
+    /// Interpolates gamma-encoded sRGB channel values
+    Srgb,
+    /// Interpolates hue, saturation and value, taking the shortest way around the hue circle
+    Hsv,
+    /// Interpolates in the perceptually uniform Oklab space, which avoids muddy mid-tones
+    Oklab,
+}
+
+impl Default for ColorSpace {
+    fn default() -> Self {
+        ColorSpace::LinearRgb
+    }
 }
 
 impl Default for Color {
@@ -289,6 +329,103 @@ fn decode_rgba(data: &[u8]) -> Result<Color, HexColorError> {
     }
 }
 
+fn linear_to_srgb(linear: Vec3) -> Vec3 {
+    let encode = |c: f32| {
+        if c <= 0.003_130_8 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    };
+    Vec3::new(encode(linear.x()), encode(linear.y()), encode(linear.z()))
+}
+
+fn srgb_to_linear(srgb: Vec3) -> Vec3 {
+    let decode = |c: f32| {
+        if c <= 0.040_45 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Vec3::new(decode(srgb.x()), decode(srgb.y()), decode(srgb.z()))
+}
+
+/// Returns (hue in degrees, saturation, value)
+fn srgb_to_hsv(srgb: Vec3) -> Vec3 {
+    let (r, g, b) = (srgb.x(), srgb.y(), srgb.z());
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+    Vec3::new(hue, saturation, max)
+}
+
+fn hsv_to_srgb(hsv: Vec3) -> Vec3 {
+    let (hue, saturation, value) = (hsv.x(), hsv.y(), hsv.z());
+    let chroma = value * saturation;
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let rgb = match sector as u32 {
+        0 => Vec3::new(chroma, x, 0.0),
+        1 => Vec3::new(x, chroma, 0.0),
+        2 => Vec3::new(0.0, chroma, x),
+        3 => Vec3::new(0.0, x, chroma),
+        4 => Vec3::new(x, 0.0, chroma),
+        _ => Vec3::new(chroma, 0.0, x),
+    };
+    rgb + Vec3::splat(value - chroma)
+}
+
+fn lerp_hsv(start: Vec3, end: Vec3, t: f32) -> Vec3 {
+    // achromatic colors have no meaningful hue, so borrow the other color's hue instead of
+    // sweeping through unrelated hues
+    let start_hue = if start.y() == 0.0 { end.x() } else { start.x() };
+    let end_hue = if end.y() == 0.0 { start_hue } else { end.x() };
+    let mut hue_delta = (end_hue - start_hue).rem_euclid(360.0);
+    if hue_delta > 180.0 {
+        hue_delta -= 360.0;
+    }
+    Vec3::new(
+        start_hue + hue_delta * t,
+        start.y() + (end.y() - start.y()) * t,
+        start.z() + (end.z() - start.z()) * t,
+    )
+}
+
+// Oklab conversions from https://bottosson.github.io/posts/oklab/
+fn linear_to_oklab(linear: Vec3) -> Vec3 {
+    let (r, g, b) = (linear.x(), linear.y(), linear.z());
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    Vec3::new(
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+fn oklab_to_linear(oklab: Vec3) -> Vec3 {
+    let (lightness, a, b) = (oklab.x(), oklab.y(), oklab.z());
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    Vec3::new(
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    )
+}
+
 #[test]
 fn test_hex_color() {
     assert_eq!(Color::hex("FFF").unwrap(), Color::rgb(1.0, 1.0, 1.0));
@@ -315,3 +452,43 @@ fn test_hex_color() {
 
     assert!(Color::hex("1234567890").is_err());
 }
+
+#[test]
+fn test_color_lerp() {
+    fn assert_approx_eq(a: Color, b: Color) {
+        let a: [f32; 4] = a.into();
+        let b: [f32; 4] = b.into();
+        for (a, b) in a.iter().zip(b.iter()) {
+            assert!((a - b).abs() < 1e-4, "{:?} != {:?}", a, b);
+        }
+    }
+
+    let start = Color::rgba(1.0, 0.0, 0.0, 0.0);
+    let end = Color::rgba(0.0, 0.0, 1.0, 1.0);
+    for &color_space in &[
+        ColorSpace::LinearRgb,
+        ColorSpace::Srgb,
+        ColorSpace::Hsv,
+        ColorSpace::Oklab,
+    ] {
+        assert_approx_eq(start.lerp(end, 0.0, color_space), start);
+        assert_approx_eq(start.lerp(end, 1.0, color_space), end);
+        assert!((start.lerp(end, 0.5, color_space).a - 0.5).abs() < 1e-6);
+    }
+
+    assert_approx_eq(
+        start.lerp(end, 0.5, ColorSpace::LinearRgb),
+        Color::rgba(0.5, 0.0, 0.5, 0.5),
+    );
+
+    // red to blue takes the short way around the hue circle, through magenta
+    assert_approx_eq(
+        start.lerp(end, 0.5, ColorSpace::Hsv),
+        Color::rgba(1.0, 0.0, 1.0, 0.5),
+    );
+
+    // sRGB mid-grey is darker in linear terms than the linear midpoint
+    let grey = Color::BLACK.lerp(Color::WHITE, 0.5, ColorSpace::Srgb);
+    assert!((grey.r - 0.214_041).abs() < 1e-4);
+    assert_eq!(grey.r, grey.g);
+}